@@ -1,24 +1,47 @@
 use chrono::{self};
-use crossterm::event::{self, KeyCode, KeyEvent};
+use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::ExecutableCommand;
 use csv::{Reader, Writer};
 use ratatui::backend::CrosstermBackend;
-use ratatui::layout::Constraint;
+use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, Cell, Row, Table};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Terminal;
 use std::cmp::{max, min};
 use std::fs::File;
 use std::io::{stdout, Result};
+use std::time::{Duration, Instant};
+
+// Number of further `q` presses, after the first one that shows the warning,
+// required to discard unsaved changes, mirroring kilo's KILO_QUIT_TIMES
+// confirmation dance.
+const QUIT_CONFIRMATIONS: u8 = 1;
+// How long a transient status message stays on screen before it expires.
+const STATUS_MESSAGE_TTL: Duration = Duration::from_secs(4);
 
 #[derive(Debug, PartialEq)]
 enum AppState {
     Navigating(usize, usize),
-    Editing(usize, usize),
-    EditingHeader(usize),
+    // Cell position plus the in-cell cursor, as a char index into the value.
+    Editing(usize, usize, usize),
+    // Column plus the in-cell cursor, as a char index into the header text.
+    EditingHeader(usize, usize),
+    // Holds the cell to return to plus the command typed so far.
+    Command(usize, usize, String),
+    // Holds the live match position plus the query typed so far.
+    Searching(usize, usize, String),
+    // Anchor cell plus the live cursor cell; the rectangle between them is
+    // the selection.
+    Visual(usize, usize, usize, usize),
+}
+
+struct StatusMessage {
+    text: String,
+    created_at: Instant,
 }
 
 pub struct CSVModel {
@@ -27,8 +50,94 @@ pub struct CSVModel {
     grid: Vec<Vec<String>>,
     state: AppState,
     running: bool,
-    working_states: Vec<(Vec<String>, Vec<Vec<String>>)>,
-    copy_buffer: Option<String>,
+    undo_stack: Vec<(Vec<String>, Vec<Vec<String>>)>,
+    redo_stack: Vec<(Vec<String>, Vec<Vec<String>>)>,
+    // A rectangular block of cells, as rows of values; single-cell yanks
+    // store a 1x1 block so `paste_from_buffer` only has one code path.
+    copy_buffer: Option<Vec<Vec<String>>>,
+    dirty: bool,
+    quit_warnings_remaining: u8,
+    status_message: Option<StatusMessage>,
+    last_search: Option<String>,
+    search_return_pos: (usize, usize),
+    // Column + direction of the last sort, so repeating the sort key toggles
+    // ascending/descending instead of always sorting ascending.
+    sort_state: Option<(usize, bool)>,
+    // Substring or `col=value` predicate; rows not matching it are hidden
+    // from `render_tui` without touching `grid` itself.
+    filter: Option<String>,
+}
+
+// Whitespace, alphanumeric (incl. `_`), and punctuation are treated as
+// distinct classes so a "word start" is a non-whitespace char whose
+// predecessor is whitespace or a different class.
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+// The start of the word (class-aware) immediately before `cursor`, skipping
+// any whitespace right before the cursor first.
+fn prev_word_boundary(chars: &[char], cursor: usize) -> usize {
+    let mut i = cursor;
+    while i > 0 && chars[i - 1].is_whitespace() {
+        i -= 1;
+    }
+    if i == 0 {
+        return 0;
+    }
+    let class = char_class(chars[i - 1]);
+    while i > 0 && !chars[i - 1].is_whitespace() && char_class(chars[i - 1]) == class {
+        i -= 1;
+    }
+    i
+}
+
+// The start of the next word (class-aware) after `cursor`.
+fn next_word_boundary(chars: &[char], cursor: usize) -> usize {
+    let len = chars.len();
+    let mut i = cursor;
+    if i < len {
+        let class = char_class(chars[i]);
+        while i < len && !chars[i].is_whitespace() && char_class(chars[i]) == class {
+            i += 1;
+        }
+    }
+    while i < len && chars[i].is_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+// A "long word" (as in vi's WORD) is any run of non-whitespace, ignoring the
+// alphanumeric/punctuation distinction; used for Ctrl-w, matching the way
+// shells delete a word back.
+fn prev_long_word_boundary(chars: &[char], cursor: usize) -> usize {
+    let mut i = cursor;
+    while i > 0 && chars[i - 1].is_whitespace() {
+        i -= 1;
+    }
+    while i > 0 && !chars[i - 1].is_whitespace() {
+        i -= 1;
+    }
+    i
+}
+
+fn insert_char_at(text: &mut String, cursor: usize, ch: char) {
+    let mut chars: Vec<char> = text.chars().collect();
+    chars.insert(cursor, ch);
+    *text = chars.into_iter().collect();
+}
+
+fn delete_char_range(text: &mut String, from: usize, to: usize) {
+    let mut chars: Vec<char> = text.chars().collect();
+    chars.drain(from..to);
+    *text = chars.into_iter().collect();
 }
 
 impl CSVModel {
@@ -54,16 +163,62 @@ impl CSVModel {
             grid,
             state: AppState::Navigating(0, 0),
             running: true,
-            working_states: Vec::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
             copy_buffer: None,
+            dirty: false,
+            quit_warnings_remaining: 0,
+            status_message: None,
+            last_search: None,
+            search_return_pos: (0, 0),
+            sort_state: None,
+            filter: None,
         })
     }
 
     fn get_current_row_and_col(&self) -> (usize, usize) {
         match self.state {
             AppState::Navigating(row, col) => (row, col),
-            AppState::Editing(row, col) => (row, col),
-            AppState::EditingHeader(col) => (0, col),
+            AppState::Editing(row, col, _) => (row, col),
+            AppState::EditingHeader(col, _) => (0, col),
+            AppState::Command(row, col, _) => (row, col),
+            AppState::Searching(row, col, _) => (row, col),
+            AppState::Visual(_, _, cursor_row, cursor_col) => (cursor_row, cursor_col),
+        }
+    }
+
+    // The inclusive row/col ranges spanned by a visual selection, regardless
+    // of which corner is the anchor and which is the live cursor.
+    fn visual_bounds(
+        anchor: (usize, usize),
+        cursor: (usize, usize),
+    ) -> (std::ops::RangeInclusive<usize>, std::ops::RangeInclusive<usize>) {
+        (
+            min(anchor.0, cursor.0)..=max(anchor.0, cursor.0),
+            min(anchor.1, cursor.1)..=max(anchor.1, cursor.1),
+        )
+    }
+
+    fn yank_visual_selection(&mut self, anchor: (usize, usize), cursor: (usize, usize)) {
+        let (row_range, col_range) = Self::visual_bounds(anchor, cursor);
+        let block = row_range
+            .map(|row| {
+                col_range
+                    .clone()
+                    .map(|col| self.grid[row][col].clone())
+                    .collect()
+            })
+            .collect();
+        self.copy_buffer = Some(block);
+    }
+
+    fn clear_visual_selection(&mut self, anchor: (usize, usize), cursor: (usize, usize)) {
+        self.save_current_state();
+        let (row_range, col_range) = Self::visual_bounds(anchor, cursor);
+        for row in row_range {
+            for col in col_range.clone() {
+                self.grid[row][col] = String::new();
+            }
         }
     }
 
@@ -108,37 +263,348 @@ impl CSVModel {
         }
     }
 
+    // Scans cells for `query`, wrapping around the grid starting at `from`.
+    // `inclusive` also checks the starting cell itself (used by incremental
+    // search); `forward` controls the scan direction (used by n/N). For an
+    // inclusive scan only, headers are checked last as a fallback, landing on
+    // row 0 of the matching column since a header isn't itself an
+    // addressable `Navigating` position; n/N never match a header.
+    fn find_match(
+        &self,
+        query: &str,
+        from: (usize, usize),
+        forward: bool,
+        inclusive: bool,
+    ) -> Option<(usize, usize)> {
+        if query.is_empty() || self.grid.is_empty() || self.headers.is_empty() {
+            return None;
+        }
+        let query_lower = query.to_lowercase();
+        let num_cols = self.headers.len();
+        let total = self.grid.len() * num_cols;
+        let start_idx = from.0 * num_cols + from.1;
+        let first_step = if inclusive { 0 } else { 1 };
+        for step in first_step..=total {
+            let offset = if forward { step } else { total - step };
+            let idx = (start_idx + offset) % total;
+            let (row, col) = (idx / num_cols, idx % num_cols);
+            if self.grid[row][col].to_lowercase().contains(&query_lower) {
+                return Some((row, col));
+            }
+        }
+        if inclusive {
+            if let Some(col) = self
+                .headers
+                .iter()
+                .position(|h| h.to_lowercase().contains(&query_lower))
+            {
+                return Some((0, col));
+            }
+        }
+        None
+    }
+
+    // Stable-sorts by `col`, comparing numerically if every value in the
+    // column parses as a number and falling back to lexicographic order
+    // otherwise.
+    fn sort_by_column(&mut self, col: usize, descending: bool) {
+        self.save_current_state();
+        let numeric = self.grid.iter().all(|row| row[col].trim().parse::<f64>().is_ok());
+        self.grid.sort_by(|a, b| {
+            let ordering = if numeric {
+                let a_val: f64 = a[col].trim().parse().unwrap();
+                let b_val: f64 = b[col].trim().parse().unwrap();
+                a_val.partial_cmp(&b_val).unwrap_or(std::cmp::Ordering::Equal)
+            } else {
+                a[col].cmp(&b[col])
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    // `col=value` matches one column's whole value, ignoring case (the column
+    // is given by exact header name or index);
+    // anything else is a substring match against any cell in the row.
+    fn row_matches_filter(&self, row: &[String], expr: &str) -> bool {
+        if let Some((col_part, value)) = expr.split_once('=') {
+            let col_part = col_part.trim();
+            let value = value.trim().to_lowercase();
+            let col_idx = col_part
+                .parse::<usize>()
+                .ok()
+                .or_else(|| self.headers.iter().position(|h| h == col_part));
+            col_idx
+                .and_then(|idx| row.get(idx))
+                .is_some_and(|v| v.to_lowercase() == value)
+        } else {
+            let needle = expr.to_lowercase();
+            row.iter().any(|v| v.to_lowercase().contains(&needle))
+        }
+    }
+
+    // Indices of the rows `render_tui` should draw, honoring `filter`
+    // without mutating `grid` so the filter can simply be cleared later.
+    fn visible_row_indices(&self) -> Vec<usize> {
+        match &self.filter {
+            None => (0..self.grid.len()).collect(),
+            Some(expr) => self
+                .grid
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| self.row_matches_filter(row, expr))
+                .map(|(i, _)| i)
+                .collect(),
+        }
+    }
+
+    fn set_filter(&mut self, expr: &str) {
+        self.filter = if expr.trim().is_empty() {
+            None
+        } else {
+            Some(expr.trim().to_string())
+        };
+    }
+
+    // Steps `delta` positions through the visible rows from `current`, so
+    // vertical navigation can't land the cursor on a row a filter has hidden.
+    // With no filter this is equivalent to `current + delta`, clamped.
+    fn move_to_visible_row(&self, current: usize, delta: isize) -> usize {
+        let visible = self.visible_row_indices();
+        if visible.is_empty() {
+            return current;
+        }
+        // If `current` sits below every visible row (e.g. a filter was
+        // applied while the cursor was on a row it now hides), treat it as
+        // one past the last visible row so a negative delta still lands on
+        // the nearest visible row instead of leaving the cursor stuck.
+        let pos = visible
+            .iter()
+            .position(|&r| r >= current)
+            .unwrap_or(visible.len());
+        let new_pos = (pos as isize + delta).clamp(0, visible.len() as isize - 1) as usize;
+        visible[new_pos]
+    }
+
     fn save_current_state(&mut self) {
         let current_headers = self.headers.clone();
         let current_grid = self.grid.clone();
-        self.working_states.push((current_headers, current_grid));
+        self.undo_stack.push((current_headers, current_grid));
+        self.redo_stack.clear();
+        self.dirty = true;
+    }
+
+    // Summarizes filename, position, mode and modified state for the status
+    // bar, preferring any still-live transient message (e.g. the quit
+    // warning) over the default summary.
+    fn status_bar_text(&mut self) -> String {
+        if let Some(message) = self.status_message_text() {
+            return message;
+        }
+        let (row, col) = self.get_current_row_and_col();
+        format!(
+            "{}{} | {} | ({}, {}) | {} rows, {} cols",
+            self.file_path,
+            if self.dirty { " [modified]" } else { "" },
+            self.mode_label(),
+            row + 1,
+            col + 1,
+            self.grid.len(),
+            self.headers.len()
+        )
+    }
+
+    // The query cells should be highlighted against, if any: the live query
+    // while typing, otherwise the last committed search.
+    fn active_query(&self) -> Option<&str> {
+        let query = match &self.state {
+            AppState::Searching(_, _, query) => Some(query.as_str()),
+            _ => self.last_search.as_deref(),
+        };
+        // An empty query would match every cell via `str::contains`, so treat
+        // it the same as no query at all rather than highlighting the grid.
+        query.filter(|q| !q.is_empty())
+    }
+
+    fn mode_label(&self) -> &'static str {
+        match self.state {
+            AppState::Navigating(_, _) => "NAVIGATE",
+            AppState::Editing(_, _, _) => "EDIT",
+            AppState::EditingHeader(_, _) => "EDIT HEADER",
+            AppState::Command(_, _, _) => "COMMAND",
+            AppState::Searching(_, _, _) => "SEARCH",
+            AppState::Visual(_, _, _, _) => "VISUAL",
+        }
+    }
+
+    // Whatever the view should render on the bottom line: the command/search
+    // bar's own prompt takes priority over the regular status summary.
+    fn bottom_bar_text(&mut self) -> String {
+        match &self.state {
+            AppState::Command(_, _, buffer) => return format!(":{}", buffer),
+            AppState::Searching(_, _, query) => return format!("/{}", query),
+            _ => {}
+        }
+        self.status_bar_text()
+    }
+
+    // Parses and runs a line typed into the `:` command bar. Unknown or
+    // out-of-range commands are reported via the status bar rather than
+    // panicking, same as a bad keybind is simply ignored elsewhere.
+    fn execute_command(&mut self, command: &str) {
+        let command = command.trim();
+        match command {
+            "w" => {
+                if let Err(err) = self.save_changes_to_file() {
+                    self.set_status_message(format!("Failed to save: {}", err));
+                } else {
+                    self.set_status_message("Saved".to_string());
+                }
+            }
+            "q" => {
+                self.running = false;
+            }
+            "wq" => {
+                if let Err(err) = self.save_changes_to_file() {
+                    self.set_status_message(format!("Failed to save: {}", err));
+                } else {
+                    self.running = false;
+                }
+            }
+            _ => {
+                if let Some(row_str) = command.strip_prefix("delrow ") {
+                    self.run_row_command(row_str, |model, index| model.delete_row(index));
+                } else if let Some(col_str) = command.strip_prefix("delcol ") {
+                    self.run_col_command(col_str, |model, index| model.delete_col(index));
+                } else if let Some(sort_args) = command.strip_prefix("sort ") {
+                    let mut parts = sort_args.split_whitespace();
+                    let col_str = parts.next().unwrap_or("");
+                    let descending = parts.next() == Some("desc");
+                    self.run_col_command(col_str, move |model, index| {
+                        model.sort_by_column(index, descending)
+                    });
+                } else if command == "filter" {
+                    self.set_filter("");
+                } else if let Some(expr) = command.strip_prefix("filter ") {
+                    self.set_filter(expr);
+                } else if let Ok(row) = command.parse::<usize>() {
+                    let (_, col) = self.get_current_row_and_col();
+                    if row >= 1 && row <= self.grid.len() {
+                        self.state = AppState::Navigating(row - 1, col);
+                    } else {
+                        self.set_status_message(format!("No such row: {}", row));
+                    }
+                } else {
+                    self.set_status_message(format!("Unknown command: {}", command));
+                }
+            }
+        }
+        self.clamp_cursor_to_grid();
+    }
+
+    fn run_row_command(&mut self, index_str: &str, action: impl FnOnce(&mut Self, usize)) {
+        match index_str.trim().parse::<usize>() {
+            Ok(index) if index < self.grid.len() => action(self, index),
+            Ok(index) => self.set_status_message(format!("No such row: {}", index)),
+            Err(_) => self.set_status_message(format!("Not a number: {}", index_str)),
+        }
+    }
+
+    fn run_col_command(&mut self, index_str: &str, action: impl FnOnce(&mut Self, usize)) {
+        match index_str.trim().parse::<usize>() {
+            Ok(index) if index < self.headers.len() => action(self, index),
+            Ok(index) => self.set_status_message(format!("No such column: {}", index)),
+            Err(_) => self.set_status_message(format!("Not a number: {}", index_str)),
+        }
+    }
+
+    fn set_status_message(&mut self, text: String) {
+        self.status_message = Some(StatusMessage {
+            text,
+            created_at: Instant::now(),
+        });
+    }
+
+    // Returns the active transient message, discarding it once it has expired.
+    fn status_message_text(&mut self) -> Option<String> {
+        if let Some(message) = &self.status_message {
+            if message.created_at.elapsed() < STATUS_MESSAGE_TTL {
+                return Some(message.text.clone());
+            }
+            self.status_message = None;
+        }
+        None
     }
 
     fn restore_last_state(&mut self) {
-        if let Some((last_headers, last_grid)) = self.working_states.pop() {
+        if let Some((last_headers, last_grid)) = self.undo_stack.pop() {
+            self.redo_stack
+                .push((self.headers.clone(), self.grid.clone()));
             self.headers = last_headers;
             self.grid = last_grid;
-            if self.get_current_row_and_col().0 >= self.grid.len() {
-                self.state = AppState::Navigating(self.grid.len() - 1, 0);
-            }
+            self.clamp_cursor_to_grid();
+        }
+    }
+
+    fn redo_last_state(&mut self) {
+        if let Some((next_headers, next_grid)) = self.redo_stack.pop() {
+            self.undo_stack
+                .push((self.headers.clone(), self.grid.clone()));
+            self.headers = next_headers;
+            self.grid = next_grid;
+            self.clamp_cursor_to_grid();
         }
     }
 
+    // Undo/redo can restore a grid that is narrower or shorter than the one
+    // the cursor was sitting in, so both axes must be clamped or indexing
+    // into `grid`/`headers` on the next render or keypress panics.
+    fn clamp_cursor_to_grid(&mut self) {
+        let (row, col) = self.get_current_row_and_col();
+        let clamped_row = min(row, self.grid.len().saturating_sub(1));
+        let clamped_col = min(col, self.headers.len().saturating_sub(1));
+        self.state = AppState::Navigating(clamped_row, clamped_col);
+    }
+
     fn copy_selected_cell_to_buffer(&mut self) {
         match self.state {
             AppState::Navigating(row, col) => {
-                self.copy_buffer = Some(self.grid[row][col].clone());
+                self.copy_buffer = Some(vec![vec![self.grid[row][col].clone()]]);
             }
             _ => {}
         }
     }
 
+    // Pastes the stored block with its top-left at the current cell, growing
+    // the grid with empty rows/columns if the block extends past the
+    // current bounds.
     fn paste_from_buffer(&mut self) {
         match self.state {
             AppState::Navigating(row, col) => {
-                if let Some(buffer) = &self.copy_buffer {
-                    self.grid[row][col] = buffer.clone();
+                let Some(block) = self.copy_buffer.clone() else {
+                    return;
+                };
+                let needed_rows = row + block.len();
+                while self.grid.len() < needed_rows {
+                    let empty_row = vec![String::new(); self.headers.len()];
+                    self.grid.push(empty_row);
+                }
+                let needed_cols = col + block.iter().map(|r| r.len()).max().unwrap_or(0);
+                while self.headers.len() < needed_cols {
+                    self.headers.push(String::new());
+                    for grid_row in self.grid.iter_mut() {
+                        grid_row.push(String::new());
+                    }
+                }
+                for (i, block_row) in block.iter().enumerate() {
+                    for (j, value) in block_row.iter().enumerate() {
+                        self.grid[row + i][col + j] = value.clone();
+                    }
                 }
+                self.dirty = true;
             }
             _ => {}
         }
@@ -149,12 +615,13 @@ impl CSVModel {
             AppState::Navigating(row, col) => {
                 self.grid[row][col] =
                     format!("{}", chrono::Local::now().format("%Y-%m-%d").to_string());
+                self.dirty = true;
             }
             _ => {}
         }
     }
 
-    fn save_changes_to_file(&self) -> Result<()> {
+    fn save_changes_to_file(&mut self) -> Result<()> {
         let file = File::create(&self.file_path)?;
         let mut wtr = Writer::from_writer(file);
 
@@ -164,22 +631,28 @@ impl CSVModel {
         }
 
         wtr.flush()?;
+        self.dirty = false;
         Ok(())
     }
 
-    fn handle_keyboard_input(&mut self, key: KeyCode) {
+    fn handle_keyboard_input(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        if key != KeyCode::Char('q') {
+            self.quit_warnings_remaining = 0;
+        }
         match self.state {
             AppState::Navigating(selected_row, selected_col) => match key {
                 // NAVIGATION
                 KeyCode::Char('j') => {
-                    if selected_row < self.grid.len() - 1 {
-                        self.state = AppState::Navigating(selected_row + 1, selected_col);
-                    }
+                    self.state = AppState::Navigating(
+                        self.move_to_visible_row(selected_row, 1),
+                        selected_col,
+                    );
                 }
                 KeyCode::Char('k') => {
-                    if selected_row > 0 {
-                        self.state = AppState::Navigating(selected_row - 1, selected_col);
-                    }
+                    self.state = AppState::Navigating(
+                        self.move_to_visible_row(selected_row, -1),
+                        selected_col,
+                    );
                 }
                 KeyCode::Char('h') => {
                     if selected_col > 0 {
@@ -193,22 +666,27 @@ impl CSVModel {
                 }
                 KeyCode::Char('}') => {
                     self.state = AppState::Navigating(
-                        min(self.grid.len() - 1, selected_row + 5),
+                        self.move_to_visible_row(selected_row, 5),
                         selected_col,
                     )
                 }
                 KeyCode::Char('{') => {
-                    if selected_row >= 5 {
-                        self.state = AppState::Navigating(max(0, selected_row - 5), selected_col)
-                    } else {
-                        self.state = AppState::Navigating(0, selected_col)
-                    }
+                    self.state = AppState::Navigating(
+                        self.move_to_visible_row(selected_row, -5),
+                        selected_col,
+                    )
                 }
                 KeyCode::Char('g') => {
-                    self.state = AppState::Navigating(0, selected_col);
+                    let top = self.visible_row_indices().first().copied().unwrap_or(0);
+                    self.state = AppState::Navigating(top, selected_col);
                 }
                 KeyCode::Char('G') => {
-                    self.state = AppState::Navigating(self.grid.len() - 1, selected_col);
+                    let bottom = self
+                        .visible_row_indices()
+                        .last()
+                        .copied()
+                        .unwrap_or(self.grid.len() - 1);
+                    self.state = AppState::Navigating(bottom, selected_col);
                 }
                 KeyCode::Char('I') => {
                     self.state = AppState::Navigating(selected_row, 0);
@@ -221,6 +699,9 @@ impl CSVModel {
                 KeyCode::Char('u') => {
                     self.restore_last_state();
                 }
+                KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.redo_last_state();
+                }
 
                 // CREATING AND DELETING ROWS AND COLUMNS
                 KeyCode::Char('o') => {
@@ -239,11 +720,11 @@ impl CSVModel {
                         self.state = AppState::Navigating(selected_row, selected_col);
                     }
                 }
-                KeyCode::Char('n') => {
+                KeyCode::Char('c') => {
                     self.insert_empty_col_after(selected_col);
                     self.state = AppState::Navigating(selected_row, selected_col + 1);
                 }
-                KeyCode::Char('N') => {
+                KeyCode::Char('C') => {
                     self.insert_empty_col_before(selected_col);
                     self.state = AppState::Navigating(selected_row, selected_col);
                 }
@@ -259,19 +740,23 @@ impl CSVModel {
                 // EDITING
                 KeyCode::Char('i') => {
                     self.save_current_state();
-                    self.state = AppState::Editing(selected_row, selected_col);
+                    let cursor = self.grid[selected_row][selected_col].chars().count();
+                    self.state = AppState::Editing(selected_row, selected_col, cursor);
                 }
                 KeyCode::Enter => {
                     self.save_current_state();
-                    self.state = AppState::Editing(selected_row, selected_col);
+                    let cursor = self.grid[selected_row][selected_col].chars().count();
+                    self.state = AppState::Editing(selected_row, selected_col, cursor);
                 }
                 KeyCode::Char('r') => {
+                    self.save_current_state();
                     self.grid[selected_row][selected_col] = String::new();
-                    self.state = AppState::Editing(selected_row, selected_col);
+                    self.state = AppState::Editing(selected_row, selected_col, 0);
                 }
                 KeyCode::Char('H') => {
                     self.save_current_state();
-                    self.state = AppState::EditingHeader(selected_col);
+                    let cursor = self.headers[selected_col].chars().count();
+                    self.state = AppState::EditingHeader(selected_col, cursor);
                 }
                 KeyCode::Char('y') => {
                     self.copy_selected_cell_to_buffer();
@@ -284,34 +769,256 @@ impl CSVModel {
                     self.paste_date();
                 }
 
+                // VISUAL SELECTION
+                KeyCode::Char('v') => {
+                    self.state = AppState::Visual(selected_row, selected_col, selected_row, selected_col);
+                }
+
+                // SORTING
+                KeyCode::Char('s') => {
+                    let descending = matches!(self.sort_state, Some((col, desc)) if col == selected_col && !desc);
+                    self.sort_by_column(selected_col, descending);
+                    self.sort_state = Some((selected_col, descending));
+                }
+
+                // COMMAND BAR
+                KeyCode::Char(':') => {
+                    self.state = AppState::Command(selected_row, selected_col, String::new());
+                }
+
+                // SEARCH
+                KeyCode::Char('/') => {
+                    self.search_return_pos = (selected_row, selected_col);
+                    self.state = AppState::Searching(selected_row, selected_col, String::new());
+                }
+                KeyCode::Char('n') => {
+                    if let Some(query) = self.last_search.clone() {
+                        if let Some((row, col)) =
+                            self.find_match(&query, (selected_row, selected_col), true, false)
+                        {
+                            self.state = AppState::Navigating(row, col);
+                        }
+                    }
+                }
+                KeyCode::Char('N') => {
+                    if let Some(query) = self.last_search.clone() {
+                        if let Some((row, col)) =
+                            self.find_match(&query, (selected_row, selected_col), false, false)
+                        {
+                            self.state = AppState::Navigating(row, col);
+                        }
+                    }
+                }
+
                 // QUIT
+                KeyCode::Char('q') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    if let Err(err) = self.save_changes_to_file() {
+                        self.set_status_message(format!("Failed to save: {}", err));
+                    } else {
+                        self.running = false;
+                    }
+                }
                 KeyCode::Char('q') => {
-                    self.save_changes_to_file().unwrap();
-                    self.running = false;
+                    if self.dirty {
+                        if self.quit_warnings_remaining == 0 {
+                            self.quit_warnings_remaining = QUIT_CONFIRMATIONS;
+                            self.set_status_message(
+                                "Unsaved changes! Press q again to quit without saving"
+                                    .to_string(),
+                            );
+                        } else {
+                            self.quit_warnings_remaining -= 1;
+                            if self.quit_warnings_remaining == 0 {
+                                self.running = false;
+                            } else {
+                                self.set_status_message(
+                                    "Unsaved changes! Press q again to quit without saving"
+                                        .to_string(),
+                                );
+                            }
+                        }
+                    } else {
+                        self.running = false;
+                    }
                 }
                 _ => {}
             },
-            AppState::Editing(row, col) => match key {
+            AppState::Editing(row, col, cursor) => match key {
                 KeyCode::Enter => {
                     self.state = AppState::Navigating(row, col);
                 }
+                KeyCode::Left if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.grid[row][col].chars().collect();
+                    self.state = AppState::Editing(row, col, prev_word_boundary(&chars, cursor));
+                }
+                KeyCode::Right if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.grid[row][col].chars().collect();
+                    self.state = AppState::Editing(row, col, next_word_boundary(&chars, cursor));
+                }
+                KeyCode::Left => {
+                    self.state = AppState::Editing(row, col, cursor.saturating_sub(1));
+                }
+                KeyCode::Right => {
+                    let len = self.grid[row][col].chars().count();
+                    self.state = AppState::Editing(row, col, min(cursor + 1, len));
+                }
+                KeyCode::Home => {
+                    self.state = AppState::Editing(row, col, 0);
+                }
+                KeyCode::End => {
+                    let len = self.grid[row][col].chars().count();
+                    self.state = AppState::Editing(row, col, len);
+                }
+                KeyCode::Char('w') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.grid[row][col].chars().collect();
+                    let start = prev_long_word_boundary(&chars, cursor);
+                    delete_char_range(&mut self.grid[row][col], start, cursor);
+                    self.dirty = true;
+                    self.state = AppState::Editing(row, col, start);
+                }
                 KeyCode::Backspace => {
-                    self.grid[row][col].pop();
+                    if cursor > 0 {
+                        delete_char_range(&mut self.grid[row][col], cursor - 1, cursor);
+                        self.dirty = true;
+                        self.state = AppState::Editing(row, col, cursor - 1);
+                    }
                 }
                 KeyCode::Char(char) => {
-                    self.grid[row][col].push(char);
+                    insert_char_at(&mut self.grid[row][col], cursor, char);
+                    self.dirty = true;
+                    self.state = AppState::Editing(row, col, cursor + 1);
                 }
                 _ => {}
             },
-            AppState::EditingHeader(col) => match key {
+            AppState::EditingHeader(col, cursor) => match key {
                 KeyCode::Enter => {
                     self.state = AppState::Navigating(0, col);
                 }
+                KeyCode::Left if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.headers[col].chars().collect();
+                    self.state = AppState::EditingHeader(col, prev_word_boundary(&chars, cursor));
+                }
+                KeyCode::Right if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.headers[col].chars().collect();
+                    self.state = AppState::EditingHeader(col, next_word_boundary(&chars, cursor));
+                }
+                KeyCode::Left => {
+                    self.state = AppState::EditingHeader(col, cursor.saturating_sub(1));
+                }
+                KeyCode::Right => {
+                    let len = self.headers[col].chars().count();
+                    self.state = AppState::EditingHeader(col, min(cursor + 1, len));
+                }
+                KeyCode::Home => {
+                    self.state = AppState::EditingHeader(col, 0);
+                }
+                KeyCode::End => {
+                    let len = self.headers[col].chars().count();
+                    self.state = AppState::EditingHeader(col, len);
+                }
+                KeyCode::Char('w') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    let chars: Vec<char> = self.headers[col].chars().collect();
+                    let start = prev_long_word_boundary(&chars, cursor);
+                    delete_char_range(&mut self.headers[col], start, cursor);
+                    self.dirty = true;
+                    self.state = AppState::EditingHeader(col, start);
+                }
                 KeyCode::Backspace => {
-                    self.headers[col].pop();
+                    if cursor > 0 {
+                        delete_char_range(&mut self.headers[col], cursor - 1, cursor);
+                        self.dirty = true;
+                        self.state = AppState::EditingHeader(col, cursor - 1);
+                    }
                 }
                 KeyCode::Char(char) => {
-                    self.headers[col].push(char);
+                    insert_char_at(&mut self.headers[col], cursor, char);
+                    self.dirty = true;
+                    self.state = AppState::EditingHeader(col, cursor + 1);
+                }
+                _ => {}
+            },
+            AppState::Command(row, col, ref buffer) => match key {
+                KeyCode::Enter => {
+                    let command = buffer.clone();
+                    self.state = AppState::Navigating(row, col);
+                    self.execute_command(&command);
+                }
+                KeyCode::Esc => {
+                    self.state = AppState::Navigating(row, col);
+                }
+                KeyCode::Backspace => {
+                    let mut buffer = buffer.clone();
+                    buffer.pop();
+                    self.state = AppState::Command(row, col, buffer);
+                }
+                KeyCode::Char(char) => {
+                    let mut buffer = buffer.clone();
+                    buffer.push(char);
+                    self.state = AppState::Command(row, col, buffer);
+                }
+                _ => {}
+            },
+            AppState::Searching(row, col, ref query) => match key {
+                KeyCode::Enter => {
+                    self.last_search = if query.is_empty() {
+                        None
+                    } else {
+                        Some(query.clone())
+                    };
+                    self.state = AppState::Navigating(row, col);
+                }
+                KeyCode::Esc => {
+                    let (return_row, return_col) = self.search_return_pos;
+                    self.state = AppState::Navigating(return_row, return_col);
+                }
+                KeyCode::Backspace => {
+                    let mut query = query.clone();
+                    query.pop();
+                    let (row, col) = self
+                        .find_match(&query, self.search_return_pos, true, true)
+                        .unwrap_or((row, col));
+                    self.state = AppState::Searching(row, col, query);
+                }
+                KeyCode::Char(char) => {
+                    let mut query = query.clone();
+                    query.push(char);
+                    let (row, col) = self
+                        .find_match(&query, self.search_return_pos, true, true)
+                        .unwrap_or((row, col));
+                    self.state = AppState::Searching(row, col, query);
+                }
+                _ => {}
+            },
+            AppState::Visual(anchor_row, anchor_col, cursor_row, cursor_col) => match key {
+                KeyCode::Char('j') => {
+                    let row = self.move_to_visible_row(cursor_row, 1);
+                    self.state = AppState::Visual(anchor_row, anchor_col, row, cursor_col);
+                }
+                KeyCode::Char('k') => {
+                    let row = self.move_to_visible_row(cursor_row, -1);
+                    self.state = AppState::Visual(anchor_row, anchor_col, row, cursor_col);
+                }
+                KeyCode::Char('h') if cursor_col > 0 => {
+                    self.state =
+                        AppState::Visual(anchor_row, anchor_col, cursor_row, cursor_col - 1);
+                }
+                KeyCode::Char('l') if cursor_col < self.grid[cursor_row].len() - 1 => {
+                    self.state =
+                        AppState::Visual(anchor_row, anchor_col, cursor_row, cursor_col + 1);
+                }
+                KeyCode::Char('y') => {
+                    self.yank_visual_selection((anchor_row, anchor_col), (cursor_row, cursor_col));
+                    self.state = AppState::Navigating(cursor_row, cursor_col);
+                }
+                KeyCode::Char('d') => {
+                    self.clear_visual_selection(
+                        (anchor_row, anchor_col),
+                        (cursor_row, cursor_col),
+                    );
+                    self.state = AppState::Navigating(cursor_row, cursor_col);
+                }
+                KeyCode::Esc => {
+                    self.state = AppState::Navigating(cursor_row, cursor_col);
                 }
                 _ => {}
             },
@@ -319,10 +1026,34 @@ impl CSVModel {
     }
 }
 
+// Width of the pinned row-number gutter column.
+const ROW_GUTTER_WIDTH: u16 = 5;
+const MIN_COLUMN_WIDTH: u16 = 4;
+const MAX_COLUMN_WIDTH: u16 = 20;
+
+// Renders `text` with a reverse-video block at `cursor` so the in-cell
+// cursor is visible while editing; a cursor past the last char gets a
+// trailing blank cell to stand on.
+fn cell_text_with_cursor(text: &str, cursor: usize) -> Line<'static> {
+    let chars: Vec<char> = text.chars().collect();
+    let before: String = chars[..cursor.min(chars.len())].iter().collect();
+    let at: String = chars
+        .get(cursor)
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| " ".to_string());
+    let after: String = chars[(cursor + 1).min(chars.len())..].iter().collect();
+    Line::from(vec![
+        Span::raw(before),
+        Span::styled(at, Style::default().add_modifier(Modifier::REVERSED)),
+        Span::raw(after),
+    ])
+}
+
 pub struct CSVView {
     terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
     model: CSVModel,
     scroll_offset: usize,
+    col_offset: usize,
 }
 
 impl CSVView {
@@ -334,64 +1065,164 @@ impl CSVView {
             terminal,
             model: CSVModel::build_from_file_path(file_path).unwrap(),
             scroll_offset: 0,
+            col_offset: 0,
         }
     }
 
-    pub fn handle_keyboard_input(&mut self, key: KeyCode) {
-        self.model.handle_keyboard_input(key);
+    pub fn handle_keyboard_input(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        self.model.handle_keyboard_input(key, modifiers);
         self.render_tui();
     }
 
+    // The longest value (header included) in each column, clamped to a
+    // sane range so one huge cell can't blow out the whole layout.
+    fn column_widths(&self) -> Vec<u16> {
+        self.model
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                let max_value_len = self
+                    .model
+                    .grid
+                    .iter()
+                    .map(|row| row[i].len())
+                    .max()
+                    .unwrap_or(0);
+                let width = max_value_len.max(header.len()) as u16 + 2;
+                width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH)
+            })
+            .collect()
+    }
+
+    // Slides `col_offset` so the selected column stays on screen, the same
+    // way `scroll_offset` is nudged to keep the selected row visible.
+    fn visible_columns(
+        &mut self,
+        col_widths: &[u16],
+        available_width: u16,
+        selected_col: usize,
+    ) -> std::ops::Range<usize> {
+        if selected_col < self.col_offset {
+            self.col_offset = selected_col;
+        }
+        loop {
+            let mut used = 0u16;
+            let mut last_visible = self.col_offset;
+            for (i, width) in col_widths.iter().enumerate().skip(self.col_offset) {
+                let needed = width + 1; // + column_spacing
+                if used + needed > available_width && i != self.col_offset {
+                    break;
+                }
+                used += needed;
+                last_visible = i;
+            }
+            if selected_col <= last_visible {
+                return self.col_offset..(last_visible + 1);
+            }
+            self.col_offset += 1;
+        }
+    }
+
     pub fn render_tui(&mut self) {
         let (selected_row, selected_col) = self.model.get_current_row_and_col();
-        let _ = self.terminal.draw(|f| {
-            let size = f.size();
-            self.scroll_offset = max(0, selected_row as i32 - size.height as i32 + 10) as usize;
-
-            let constraints = vec![Constraint::Length(5)]
-                .into_iter()
-                .chain(
-                    std::iter::repeat(Constraint::Percentage(
-                        (100 / (self.model.grid[0].len())) as u16,
-                    ))
-                    .take(self.model.grid[0].len()),
-                )
-                .collect::<Vec<_>>();
+        let status_bar_text = self.model.bottom_bar_text();
+        if self.model.headers.is_empty() {
+            let _ = self.terminal.draw(|f| {
+                let status_bar = Paragraph::new(status_bar_text.clone())
+                    .style(Style::default().bg(Color::DarkGray));
+                f.render_widget(status_bar, f.size());
+            });
+            return;
+        }
+        let col_widths = self.column_widths();
+        let visible_rows = self.model.visible_row_indices();
+        // The offset/range computation needs `&mut self` (to slide col_offset
+        // and scroll_offset), so it has to happen before `self.terminal.draw`
+        // takes its own mutable borrow of `self.terminal`.
+        let size = self.terminal.size().unwrap_or_default();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(size);
+        let table_area = chunks[0];
+        let status_area = chunks[1];
+        let selected_display_row = visible_rows
+            .iter()
+            .position(|&row| row == selected_row)
+            .unwrap_or(0);
+        self.scroll_offset =
+            max(0, selected_display_row as i32 - table_area.height as i32 + 10) as usize;
+        let visible_cols = self.visible_columns(
+            &col_widths,
+            table_area.width.saturating_sub(ROW_GUTTER_WIDTH),
+            selected_col,
+        );
+        let constraints = std::iter::once(Constraint::Length(ROW_GUTTER_WIDTH))
+            .chain(visible_cols.clone().map(|i| Constraint::Length(col_widths[i])))
+            .collect::<Vec<_>>();
 
+        let _ = self.terminal.draw(|f| {
             let header_cells = std::iter::once(
                 Cell::from("").style(Style::default().add_modifier(Modifier::BOLD)),
             )
-            .chain(self.model.headers.iter().enumerate().map(|(i, h)| {
+            .chain(visible_cols.clone().map(|i| {
+                let h = &self.model.headers[i];
                 match self.model.state {
-                    AppState::EditingHeader(col) if i == col => Cell::from(h.clone()).style(
-                        Style::default()
-                            .add_modifier(Modifier::BOLD)
-                            .bg(Color::Green),
-                    ),
+                    AppState::EditingHeader(col, cursor) if i == col => {
+                        Cell::from(cell_text_with_cursor(h, cursor)).style(
+                            Style::default()
+                                .add_modifier(Modifier::BOLD)
+                                .bg(Color::Green),
+                        )
+                    }
                     _ => Cell::from(h.clone()).style(Style::default().add_modifier(Modifier::BOLD)),
                 }
             }));
             let header_row = Row::new(header_cells).height(1);
 
-            let rows = self
-                .model
-                .grid
+            let query_lower = self.model.active_query().map(|q| q.to_lowercase());
+
+            let rows = visible_rows
                 .iter()
-                .enumerate()
                 .skip(self.scroll_offset)
-                .map(|(i, item)| {
+                .map(|&i| {
+                    let item = &self.model.grid[i];
                     let row_number_cell =
                         Cell::from((i + 1).to_string()).style(Style::default().fg(Color::White));
-                    let cells = item.iter().enumerate().map(|(j, c)| {
-                        let mut cell = Cell::from(c.clone());
+                    let cells = visible_cols.clone().map(|j| {
+                        let c = &item[j];
+                        let is_selected = i == selected_row && j == selected_col;
+                        let mut cell = match (is_selected, &self.model.state) {
+                            (true, AppState::Editing(_, _, cursor)) => {
+                                Cell::from(cell_text_with_cursor(c, *cursor))
+                            }
+                            _ => Cell::from(c.clone()),
+                        };
+                        if query_lower
+                            .as_ref()
+                            .is_some_and(|q| c.to_lowercase().contains(q))
+                        {
+                            cell = cell.style(Style::default().bg(Color::Yellow));
+                        }
+                        if let AppState::Visual(ar, ac, cr, cc) = self.model.state {
+                            let (row_range, col_range) =
+                                CSVModel::visual_bounds((ar, ac), (cr, cc));
+                            if row_range.contains(&i) && col_range.contains(&j) {
+                                cell = cell.style(Style::default().bg(Color::Magenta));
+                            }
+                        }
                         if i == selected_row && j == selected_col {
                             match self.model.state {
                                 AppState::Navigating(_, _) => {
                                     cell = cell.style(Style::default().bg(Color::Blue));
                                 }
-                                AppState::Editing(_, _) => {
+                                AppState::Editing(_, _, _) => {
                                     cell = cell.style(Style::default().bg(Color::Green));
                                 }
+                                AppState::Visual(_, _, _, _) => {
+                                    cell = cell.style(Style::default().bg(Color::LightMagenta));
+                                }
                                 _ => {}
                             }
                         }
@@ -406,7 +1237,11 @@ impl CSVView {
                 .block(Block::default().borders(Borders::ALL))
                 .column_spacing(1);
 
-            f.render_widget(table, size);
+            f.render_widget(table, table_area);
+
+            let status_bar =
+                Paragraph::new(status_bar_text.clone()).style(Style::default().bg(Color::DarkGray));
+            f.render_widget(status_bar, status_area);
         });
     }
 
@@ -416,9 +1251,15 @@ impl CSVView {
         self.terminal.clear()?;
         while self.model.running {
             self.render_tui();
-            if let event::Event::Key(KeyEvent { code, kind, .. }) = event::read()? {
+            if let event::Event::Key(KeyEvent {
+                code,
+                kind,
+                modifiers,
+                ..
+            }) = event::read()?
+            {
                 if kind == event::KeyEventKind::Press {
-                    self.handle_keyboard_input(code);
+                    self.handle_keyboard_input(code, modifiers);
                 }
             }
         }